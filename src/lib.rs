@@ -20,7 +20,7 @@ extern crate mio;
 extern crate mio_serial;
 
 // Re-export serialport types and traits from mio_serial
-pub use mio_serial::{BaudRate, DataBits, StopBits, FlowControl, Parity, SerialPort,
+pub use mio_serial::{BaudRate, ClearBuffer, DataBits, StopBits, FlowControl, Parity, SerialPort,
                      SerialPortSettings, SerialResult};
 
 #[cfg(unix)]
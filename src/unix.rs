@@ -0,0 +1,379 @@
+use futures::Async;
+use tokio_core::reactor::{PollEvented, Handle};
+use tokio_core::io::Io;
+
+use std::io::{self, Read, Write};
+use std::path::Path;
+use std::os::unix::io::{AsRawFd, RawFd};
+use std::time::Duration;
+
+use mio_serial;
+
+/// Serial port I/O struct.
+pub struct Serial {
+    io: PollEvented<mio_serial::Serial>,
+}
+
+impl Serial {
+    /// Open serial port from a provided path.
+    pub fn from_path<P>(path: P,
+                        settings: &mio_serial::SerialPortSettings,
+                        handle: &Handle)
+                        -> io::Result<Serial>
+        where P: AsRef<Path>
+    {
+
+        let port = mio_serial::Serial::from_path(path.as_ref(), settings)?;
+        let io = PollEvented::new(port, handle)?;
+
+        Ok(Serial { io: io })
+    }
+
+    /// Create a pair of connected, in-memory pseudo-terminal serial ports.
+    ///
+    /// This is useful for testing codecs and protocol stacks without real
+    /// hardware: each end can be driven from its own task inside the
+    /// tokio-core reactor exactly like a real device.
+    pub fn pair(handle: &Handle) -> io::Result<(Serial, Serial)> {
+        let (master, slave) = mio_serial::Serial::pair()?;
+
+        let master_io = PollEvented::new(master, handle)?;
+        let slave_io = PollEvented::new(slave, handle)?;
+
+        Ok((Serial { io: master_io }, Serial { io: slave_io }))
+    }
+
+    /// Adopt an already-open, already-configured blocking `TTYPort`.
+    ///
+    /// This lets callers who opened and configured a port with the blocking
+    /// `serialport` API, including platform-specific options not covered by
+    /// `SerialPortSettings`, hand the live port to tokio-serial without
+    /// reopening the device, which would lose exclusive locks and line
+    /// state.
+    pub fn from_serial(serial: mio_serial::TTYPort, handle: &Handle) -> io::Result<Serial> {
+        let port = mio_serial::Serial::from_serial(serial)?;
+        let io = PollEvented::new(port, handle)?;
+
+        Ok(Serial { io: io })
+    }
+
+    /// Attempts to clone the underlying port handle, registering the clone
+    /// with its own reactor handle so it can be driven from a separate task.
+    ///
+    /// This allows full-duplex use: one task can read from one clone while
+    /// another writes to the other. Note that the two clones share the same
+    /// underlying device, so settings changes made on one affect the other.
+    pub fn try_clone(&self, handle: &Handle) -> io::Result<Serial> {
+        let port = self.io.get_ref().try_clone()?;
+        let io = PollEvented::new(port, handle)?;
+
+        Ok(Serial { io: io })
+    }
+
+    /// Test whether this serial port is ready to be read or not.
+    ///
+    /// If the serial port is *not* readable then the current task is scheduled to
+    /// get a notification when the socket does become readable. That is, this
+    /// is only suitable for calling in a `Future::poll` method and will
+    /// automatically handle ensuring a retry once the socket is readable again.
+    pub fn poll_read(&self) -> Async<()> {
+        self.io.poll_read()
+    }
+
+    /// Test whether this socket is ready to be written to or not.
+    ///
+    /// If the socket is *not* writable then the current task is scheduled to
+    /// get a notification when the socket does become writable. That is, this
+    /// is only suitable for calling in a `Future::poll` method and will
+    /// automatically handle ensuring a retry once the socket is writable again.
+    pub fn poll_write(&self) -> Async<()> {
+        self.io.poll_write()
+    }
+}
+
+impl ::SerialPort for Serial {
+    /// Returns a struct with the current port settings
+    fn settings(&self) -> ::SerialPortSettings {
+        self.io.get_ref().settings()
+    }
+
+    /// Return the name associated with the serial port, if known.
+    fn port_name(&self) -> Option<String> {
+        self.io.get_ref().port_name()
+    }
+
+    /// Returns the current baud rate.
+    ///
+    /// This function returns `None` if the baud rate could not be determined. This may occur if
+    /// the hardware is in an uninitialized state. Setting a baud rate with `set_baud_rate()`
+    /// should initialize the baud rate to a supported value.
+    fn baud_rate(&self) -> Option<::BaudRate> {
+        self.io.get_ref().baud_rate()
+    }
+
+    /// Returns the character size.
+    ///
+    /// This function returns `None` if the character size could not be determined. This may occur
+    /// if the hardware is in an uninitialized state or is using a non-standard character size.
+    /// Setting a character size with `set_data_bits()` should initialize the character size to a
+    /// supported value.
+    fn data_bits(&self) -> Option<::DataBits> {
+        self.io.get_ref().data_bits()
+    }
+
+    /// Returns the flow control mode.
+    ///
+    /// This function returns `None` if the flow control mode could not be determined. This may
+    /// occur if the hardware is in an uninitialized state or is using an unsupported flow control
+    /// mode. Setting a flow control mode with `set_flow_control()` should initialize the flow
+    /// control mode to a supported value.
+    fn flow_control(&self) -> Option<::FlowControl> {
+        self.io.get_ref().flow_control()
+    }
+
+    /// Returns the parity-checking mode.
+    ///
+    /// This function returns `None` if the parity mode could not be determined. This may occur if
+    /// the hardware is in an uninitialized state or is using a non-standard parity mode. Setting
+    /// a parity mode with `set_parity()` should initialize the parity mode to a supported value.
+    fn parity(&self) -> Option<::Parity> {
+        self.io.get_ref().parity()
+    }
+
+    /// Returns the number of stop bits.
+    ///
+    /// This function returns `None` if the number of stop bits could not be determined. This may
+    /// occur if the hardware is in an uninitialized state or is using an unsupported stop bit
+    /// configuration. Setting the number of stop bits with `set_stop_bits()` should initialize the
+    /// stop bits to a supported value.
+    fn stop_bits(&self) -> Option<::StopBits> {
+        self.io.get_ref().stop_bits()
+    }
+
+    /// Returns the current timeout.
+    fn timeout(&self) -> Duration {
+        Duration::from_secs(0)
+    }
+
+    /// Gets the number of bytes available to be read from the input buffer.
+    fn bytes_to_read(&self) -> ::SerialResult<u32> {
+        self.io.get_ref().bytes_to_read()
+    }
+
+    /// Gets the number of bytes written to the output buffer, awaiting transmission.
+    fn bytes_to_write(&self) -> ::SerialResult<u32> {
+        self.io.get_ref().bytes_to_write()
+    }
+
+    /// Discards all bytes from the serial driver's input buffer, output buffer, or both.
+    fn clear(&self, buffer_to_clear: ::ClearBuffer) -> ::SerialResult<()> {
+        self.io.get_ref().clear(buffer_to_clear)
+    }
+
+    // Port settings setters
+
+    /// Applies all settings for a struct. This isn't guaranteed to involve only
+    /// a single call into the driver, though that may be done on some
+    /// platforms.
+    fn set_all(&mut self, settings: &::SerialPortSettings) -> ::SerialResult<()> {
+        self.io.get_mut().set_all(settings)
+    }
+
+    /// Sets the baud rate.
+    ///
+    /// ## Errors
+    ///
+    /// If the implementation does not support the requested baud rate, this function may return an
+    /// `InvalidInput` error. Even if the baud rate is accepted by `set_baud_rate()`, it may not be
+    /// supported by the underlying hardware.
+    fn set_baud_rate(&mut self, baud_rate: ::BaudRate) -> ::SerialResult<()> {
+        self.io.get_mut().set_baud_rate(baud_rate)
+    }
+
+    /// Sets the character size.
+    fn set_data_bits(&mut self, data_bits: ::DataBits) -> ::SerialResult<()> {
+        self.io.get_mut().set_data_bits(data_bits)
+    }
+
+    /// Sets the flow control mode.
+    fn set_flow_control(&mut self, flow_control: ::FlowControl) -> ::SerialResult<()> {
+        self.io.get_mut().set_flow_control(flow_control)
+    }
+
+    /// Sets the parity-checking mode.
+    fn set_parity(&mut self, parity: ::Parity) -> ::SerialResult<()> {
+        self.io.get_mut().set_parity(parity)
+    }
+
+    /// Sets the number of stop bits.
+    fn set_stop_bits(&mut self, stop_bits: ::StopBits) -> ::SerialResult<()> {
+        self.io.get_mut().set_stop_bits(stop_bits)
+    }
+
+    /// Sets the timeout for future I/O operations.  This parameter is ignored but
+    /// required for trait completeness.
+    fn set_timeout(&mut self, _: Duration) -> ::SerialResult<()> {
+        Ok(())
+    }
+
+    // Functions for setting non-data control signal pins
+
+    /// Sets the state of the RTS (Request To Send) control signal.
+    ///
+    /// Setting a value of `true` asserts the RTS control signal. `false` clears the signal.
+    ///
+    /// ## Errors
+    ///
+    /// This function returns an error if the RTS control signal could not be set to the desired
+    /// state on the underlying hardware:
+    ///
+    /// * `NoDevice` if the device was disconnected.
+    /// * `Io` for any other type of I/O error.
+    fn write_request_to_send(&mut self, level: bool) -> ::SerialResult<()> {
+        self.io.get_mut().write_request_to_send(level)
+    }
+
+    /// Writes to the Data Terminal Ready pin
+    ///
+    /// Setting a value of `true` asserts the DTR control signal. `false` clears the signal.
+    ///
+    /// ## Errors
+    ///
+    /// This function returns an error if the DTR control signal could not be set to the desired
+    /// state on the underlying hardware:
+    ///
+    /// * `NoDevice` if the device was disconnected.
+    /// * `Io` for any other type of I/O error.
+    fn write_data_terminal_ready(&mut self, level: bool) -> ::SerialResult<()> {
+        self.io.get_mut().write_data_terminal_ready(level)
+    }
+
+    // Functions for reading additional pins
+
+    /// Reads the state of the CTS (Clear To Send) control signal.
+    ///
+    /// This function returns a boolean that indicates whether the CTS control signal is asserted.
+    ///
+    /// ## Errors
+    ///
+    /// This function returns an error if the state of the CTS control signal could not be read
+    /// from the underlying hardware:
+    ///
+    /// * `NoDevice` if the device was disconnected.
+    /// * `Io` for any other type of I/O error.
+    fn read_clear_to_send(&mut self) -> ::SerialResult<bool> {
+        self.io.get_mut().read_clear_to_send()
+    }
+
+    /// Reads the state of the Data Set Ready control signal.
+    ///
+    /// This function returns a boolean that indicates whether the DSR control signal is asserted.
+    ///
+    /// ## Errors
+    ///
+    /// This function returns an error if the state of the DSR control signal could not be read
+    /// from the underlying hardware:
+    ///
+    /// * `NoDevice` if the device was disconnected.
+    /// * `Io` for any other type of I/O error.
+    fn read_data_set_ready(&mut self) -> ::SerialResult<bool> {
+        self.io.get_mut().read_data_set_ready()
+    }
+
+    /// Reads the state of the Ring Indicator control signal.
+    ///
+    /// This function returns a boolean that indicates whether the RI control signal is asserted.
+    ///
+    /// ## Errors
+    ///
+    /// This function returns an error if the state of the RI control signal could not be read from
+    /// the underlying hardware:
+    ///
+    /// * `NoDevice` if the device was disconnected.
+    /// * `Io` for any other type of I/O error.
+    fn read_ring_indicator(&mut self) -> ::SerialResult<bool> {
+        self.io.get_mut().read_ring_indicator()
+    }
+
+    /// Reads the state of the Carrier Detect control signal.
+    ///
+    /// This function returns a boolean that indicates whether the CD control signal is asserted.
+    ///
+    /// ## Errors
+    ///
+    /// This function returns an error if the state of the CD control signal could not be read from
+    /// the underlying hardware:
+    ///
+    /// * `NoDevice` if the device was disconnected.
+    /// * `Io` for any other type of I/O error.
+    fn read_carrier_detect(&mut self) -> ::SerialResult<bool> {
+        self.io.get_mut().read_carrier_detect()
+    }
+
+    // Functions for transmitting/clearing the BREAK condition
+
+    /// Start transmitting a break.
+    fn set_break(&self) -> ::SerialResult<()> {
+        self.io.get_ref().set_break()
+    }
+
+    /// Stop transmitting a break.
+    fn clear_break(&self) -> ::SerialResult<()> {
+        self.io.get_ref().clear_break()
+    }
+}
+
+impl Read for Serial {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        self.io.read(buf)
+    }
+}
+
+impl Write for Serial {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.io.write(buf)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.io.flush()
+    }
+}
+
+impl Io for Serial {
+    fn poll_read(&mut self) -> Async<()> {
+        <Serial>::poll_read(self)
+    }
+
+    fn poll_write(&mut self) -> Async<()> {
+        <Serial>::poll_write(self)
+    }
+}
+
+impl AsRawFd for Serial {
+    fn as_raw_fd(&self) -> RawFd {
+        self.io.get_ref().as_raw_fd()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures::Future;
+    use tokio_core::io::{read_exact, write_all};
+    use tokio_core::reactor::Core;
+
+    #[test]
+    fn pair_round_trip() {
+        let mut core = Core::new().unwrap();
+        let handle = core.handle();
+
+        let (master, slave) = Serial::pair(&handle).unwrap();
+
+        let client = write_all(master, b"hello".to_vec());
+        let server = read_exact(slave, [0u8; 5]);
+
+        let (_, (_, buf)) = core.run(client.join(server)).unwrap();
+
+        assert_eq!(&buf, b"hello");
+    }
+}
@@ -29,6 +29,49 @@ impl Serial {
         Ok(Serial { io: io })
     }
 
+    /// Create a pair of connected, in-memory pseudo-terminal serial ports.
+    ///
+    /// This is useful for testing codecs and protocol stacks without real
+    /// hardware, but pseudo-terminals are a unix-only concept. On Windows
+    /// this always fails with an `Other` error, since `ErrorKind::Unsupported`
+    /// is not available on this crate's minimum supported Rust version.
+    pub fn pair(_handle: &Handle) -> io::Result<(Serial, Serial)> {
+        Err(io::Error::new(io::ErrorKind::Other,
+                            "Serial::pair() is not supported on Windows"))
+    }
+
+    /// Adopt an already-open, already-configured serial port handle.
+    ///
+    /// This lets callers who opened and configured a port with the blocking
+    /// `serialport`/`mio_serial` API, including platform-specific options not
+    /// covered by `SerialPortSettings`, hand the live handle to tokio-serial
+    /// without reopening the device, which would lose exclusive locks and
+    /// line state.
+    ///
+    /// # Safety
+    ///
+    /// `raw_handle` must refer to a valid, open serial port handle that is
+    /// not concurrently owned elsewhere.
+    pub unsafe fn from_raw_handle(raw_handle: RawHandle, handle: &Handle) -> io::Result<Serial> {
+        let port = mio_serial::Serial::from_raw_handle(raw_handle);
+        let io = PollEvented::new(port, handle)?;
+
+        Ok(Serial { io: io })
+    }
+
+    /// Attempts to clone the underlying port handle, registering the clone
+    /// with its own reactor handle so it can be driven from a separate task.
+    ///
+    /// This allows full-duplex use: one task can read from one clone while
+    /// another writes to the other. Note that the two clones share the same
+    /// underlying device, so settings changes made on one affect the other.
+    pub fn try_clone(&self, handle: &Handle) -> io::Result<Serial> {
+        let port = self.io.get_ref().try_clone()?;
+        let io = PollEvented::new(port, handle)?;
+
+        Ok(Serial { io: io })
+    }
+
     /// Test whether this serial port is ready to be read or not.
     ///
     /// If the serial port is *not* readable then the current task is scheduled to
@@ -114,6 +157,21 @@ impl ::SerialPort for Serial {
         Duration::from_secs(0)
     }
 
+    /// Gets the number of bytes available to be read from the input buffer.
+    fn bytes_to_read(&self) -> ::SerialResult<u32> {
+        self.io.get_ref().bytes_to_read()
+    }
+
+    /// Gets the number of bytes written to the output buffer, awaiting transmission.
+    fn bytes_to_write(&self) -> ::SerialResult<u32> {
+        self.io.get_ref().bytes_to_write()
+    }
+
+    /// Discards all bytes from the serial driver's input buffer, output buffer, or both.
+    fn clear(&self, buffer_to_clear: ::ClearBuffer) -> ::SerialResult<()> {
+        self.io.get_ref().clear(buffer_to_clear)
+    }
+
     // Port settings setters
 
     /// Applies all settings for a struct. This isn't guaranteed to involve only
@@ -253,6 +311,18 @@ impl ::SerialPort for Serial {
     fn read_carrier_detect(&mut self) -> ::SerialResult<bool> {
         self.io.get_mut().read_carrier_detect()
     }
+
+    // Functions for transmitting/clearing the BREAK condition
+
+    /// Start transmitting a break.
+    fn set_break(&self) -> ::SerialResult<()> {
+        self.io.get_ref().set_break()
+    }
+
+    /// Stop transmitting a break.
+    fn clear_break(&self) -> ::SerialResult<()> {
+        self.io.get_ref().clear_break()
+    }
 }
 
 impl Read for Serial {